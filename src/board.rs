@@ -1,4 +1,26 @@
 use std::cmp::{max, min};
+use std::sync::OnceLock;
+
+/// Deterministically-seeded random constants used to Zobrist-hash a position.
+struct Zobrist {
+    /// Keyed by `[piece-color index][square]`, white pieces 0..6, black 6..12.
+    pieces: [[u64; 64]; 12],
+    /// One key per castling right, in FEN `KQkq` order.
+    castling: [u64; 4],
+    /// One key per en-passant file.
+    en_passant: [u64; 8],
+    /// Mixed into the hash when Black is to move.
+    side_to_move: u64,
+}
+
+/// A constant-seeded splitmix64 step — a small, reproducible PRNG.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
 
 #[derive(Debug, PartialEq)]
 pub enum ChessError {
@@ -8,8 +30,13 @@ pub enum ChessError {
     MoveFromEnemyPiece,
     IllegalMove,
     MoveToTeamPiece,
+    InvalidFen,
+    KingInCheck,
+    InvalidEnPassant,
+    NotInHoldings,
 }
 
+#[derive(Clone, Copy, PartialEq)]
 pub enum Team {
     White,
     Black,
@@ -32,8 +59,89 @@ pub enum Piece {
     King,
 }
 
-#[derive(PartialEq)]
-pub struct Board([[SquareState; 8]; 8]);
+/// Per-side castling availability. A right is cleared once the king or the
+/// relevant rook leaves its home square.
+#[derive(Clone, Copy, PartialEq)]
+pub struct CastlingRights {
+    pub white_king_side: bool,
+    pub white_queen_side: bool,
+    pub black_king_side: bool,
+    pub black_queen_side: bool,
+}
+
+impl CastlingRights {
+    /// All four rights available, as in the starting position.
+    pub fn all() -> Self {
+        Self {
+            white_king_side: true,
+            white_queen_side: true,
+            black_king_side: true,
+            black_queen_side: true,
+        }
+    }
+
+    /// No rights available.
+    pub fn none() -> Self {
+        Self {
+            white_king_side: false,
+            white_queen_side: false,
+            black_king_side: false,
+            black_queen_side: false,
+        }
+    }
+}
+
+/// A side's captured-piece pocket for crazyhouse/bughouse play: the count of
+/// each droppable piece type it holds. Kings are never captured, so none is
+/// tracked.
+#[derive(Clone, Copy, PartialEq, Default)]
+pub struct Holdings {
+    pub pawn: u8,
+    pub knight: u8,
+    pub bishop: u8,
+    pub rook: u8,
+    pub queen: u8,
+}
+
+impl Holdings {
+    /// A mutable handle on the count for `piece`, or `None` for the king.
+    fn count_mut(&mut self, piece: Piece) -> Option<&mut u8> {
+        use Piece::*;
+
+        match piece {
+            Pawn => Some(&mut self.pawn),
+            Knight => Some(&mut self.knight),
+            Bishop => Some(&mut self.bishop),
+            Rook => Some(&mut self.rook),
+            Queen => Some(&mut self.queen),
+            King => None,
+        }
+    }
+}
+
+/// The grid, the castling rights, the en-passant target square (the square a
+/// pawn skipped over on its last two-square advance, if any), each side's
+/// captured-piece holdings indexed `[White, Black]`, a parallel grid marking
+/// which occupied squares hold a promoted pawn, and the side to move as set
+/// when the board was built (White for `new`, the FEN's side for `from_fen`).
+/// `move_piece` and `drop_piece` do not change the side to move.
+#[derive(Clone, PartialEq)]
+pub struct Board(
+    [[SquareState; 8]; 8],
+    CastlingRights,
+    Option<(usize, usize)>,
+    [Holdings; 2],
+    [[bool; 8]; 8],
+    Team,
+);
+
+/// Index into the per-side holdings array.
+fn team_index(turn: Team) -> usize {
+    match turn {
+        Team::White => 0,
+        Team::Black => 1,
+    }
+}
 
 impl Board {
     pub fn new() -> Self {
@@ -60,33 +168,670 @@ impl Board {
             board[6][col] = Black(Pawn);
         }
 
-        Self(board)
+        Self(board, CastlingRights::all(), None, [Holdings::default(); 2], [[false; 8]; 8], Team::White)
+    }
+
+    /// Builds a `Board` from a Forsyth–Edwards Notation string.
+    ///
+    /// The six space-separated fields are consumed: piece placement (ranks
+    /// from 8 down to 1), the side to move, castling availability, the
+    /// en-passant target square, and the halfmove/fullmove counters. The
+    /// placement, side to move, castling rights, and en-passant target are
+    /// retained on the board; the halfmove/fullmove counters are only
+    /// validated for well-formedness. FEN rank 8 maps to index 7 so that
+    /// rank 1 — White's back rank — lands on index 0.
+    pub fn from_fen(fen: &str) -> Result<Board, ChessError> {
+        use Piece::*;
+        use SquareState::*;
+
+        let fields: Vec<&str> = fen.split_whitespace().collect();
+        if fields.len() != 6 {
+            return Err(ChessError::InvalidFen);
+        }
+
+        let ranks: Vec<&str> = fields[0].split('/').collect();
+        if ranks.len() != 8 {
+            return Err(ChessError::InvalidFen);
+        }
+
+        let mut board = [[Empty; 8]; 8];
+        let mut white_kings = 0;
+        let mut black_kings = 0;
+
+        for (i, rank) in ranks.iter().enumerate() {
+            let row = 7 - i;
+            let mut col = 0;
+            for ch in rank.chars() {
+                if let Some(skip) = ch.to_digit(10) {
+                    col += skip as usize;
+                    continue;
+                }
+                if col >= 8 {
+                    return Err(ChessError::InvalidFen);
+                }
+                let piece = match ch.to_ascii_lowercase() {
+                    'p' => Pawn,
+                    'n' => Knight,
+                    'b' => Bishop,
+                    'r' => Rook,
+                    'q' => Queen,
+                    'k' => King,
+                    _ => return Err(ChessError::InvalidFen),
+                };
+                if piece == King {
+                    if ch.is_ascii_uppercase() {
+                        white_kings += 1;
+                    } else {
+                        black_kings += 1;
+                    }
+                }
+                board[row][col] = if ch.is_ascii_uppercase() {
+                    White(piece)
+                } else {
+                    Black(piece)
+                };
+                col += 1;
+            }
+            if col != 8 {
+                return Err(ChessError::InvalidFen);
+            }
+        }
+
+        if white_kings != 1 || black_kings != 1 {
+            return Err(ChessError::InvalidFen);
+        }
+
+        // Side to move.
+        let side_to_move = match fields[1] {
+            "w" => Team::White,
+            "b" => Team::Black,
+            _ => return Err(ChessError::InvalidFen),
+        };
+
+        // Castling availability.
+        let castling = if fields[2] == "-" {
+            CastlingRights::none()
+        } else {
+            if !fields[2].chars().all(|c| matches!(c, 'K' | 'Q' | 'k' | 'q')) {
+                return Err(ChessError::InvalidFen);
+            }
+            CastlingRights {
+                white_king_side: fields[2].contains('K'),
+                white_queen_side: fields[2].contains('Q'),
+                black_king_side: fields[2].contains('k'),
+                black_queen_side: fields[2].contains('q'),
+            }
+        };
+
+        // En-passant target square (file-letter then rank-digit, e.g. "e3").
+        let en_passant = if fields[3] == "-" {
+            None
+        } else {
+            let mut bytes = fields[3].bytes();
+            match (bytes.next(), bytes.next(), bytes.next()) {
+                (Some(file @ b'a'..=b'h'), Some(rank @ b'1'..=b'8'), None) => {
+                    Some(((rank - b'1') as usize, (file - b'a') as usize))
+                }
+                _ => return Err(ChessError::InvalidFen),
+            }
+        };
+
+        // Halfmove and fullmove counters.
+        if fields[4].parse::<u32>().is_err() || fields[5].parse::<u32>().is_err() {
+            return Err(ChessError::InvalidFen);
+        }
+
+        Ok(Self(board, castling, en_passant, [Holdings::default(); 2], [[false; 8]; 8], side_to_move))
+    }
+
+    /// Serializes the current position back into a FEN string, collapsing runs
+    /// of `Empty` squares into digit counts and emitting the stored side to
+    /// move (as set at construction; moves do not update it), castling
+    /// rights, and en-passant target. The halfmove and
+    /// fullmove counters are emitted with their starting-position defaults, as
+    /// they are not tracked on the board.
+    pub fn to_fen(&self) -> String {
+        use SquareState::*;
+
+        let mut placement = String::new();
+        for i in 0..8 {
+            let row = 7 - i;
+            let mut empties = 0;
+            for col in 0..8 {
+                match self.0[row][col] {
+                    Empty => empties += 1,
+                    White(p) | Black(p) => {
+                        if empties > 0 {
+                            placement.push_str(&empties.to_string());
+                            empties = 0;
+                        }
+                        let letter = match p {
+                            Piece::Pawn => 'p',
+                            Piece::Rook => 'r',
+                            Piece::Knight => 'n',
+                            Piece::Bishop => 'b',
+                            Piece::Queen => 'q',
+                            Piece::King => 'k',
+                        };
+                        placement.push(match self.0[row][col] {
+                            White(_) => letter.to_ascii_uppercase(),
+                            _ => letter,
+                        });
+                    }
+                }
+            }
+            if empties > 0 {
+                placement.push_str(&empties.to_string());
+            }
+            if i != 7 {
+                placement.push('/');
+            }
+        }
+
+        let mut castling = String::new();
+        if self.1.white_king_side {
+            castling.push('K');
+        }
+        if self.1.white_queen_side {
+            castling.push('Q');
+        }
+        if self.1.black_king_side {
+            castling.push('k');
+        }
+        if self.1.black_queen_side {
+            castling.push('q');
+        }
+        if castling.is_empty() {
+            castling.push('-');
+        }
+
+        let en_passant = match self.2 {
+            Some((row, col)) => format!(
+                "{}{}",
+                (b'a' + col as u8) as char,
+                (b'1' + row as u8) as char
+            ),
+            None => "-".to_string(),
+        };
+
+        let side_to_move = match self.5 {
+            Team::White => "w",
+            Team::Black => "b",
+        };
+
+        format!("{} {} {} {} 0 1", placement, side_to_move, castling, en_passant)
     }
 
     pub fn move_piece(
         &mut self,
         turn: Team,
         from: &str,
-        to: &str
+        to: &str,
+        promotion: Option<Piece>,
     ) -> Result<Option<Piece>, ChessError>{
+        use Piece::*;
         use SquareState::*;
 
         let (r1, c1) = Board::convert_square_number(from)?;
         let (r2, c2) = Board::convert_square_number(to)?;
 
+        // A pawn may only promote to a Queen, Rook, Bishop, or Knight.
+        if matches!(promotion, Some(King) | Some(Pawn)) {
+            return Err(ChessError::IllegalMove);
+        }
+
+        // A pawn reaching the last rank must be promoted, not left as a pawn.
+        if matches!((self.0[r1][c1], r2), (White(Pawn), 7) | (Black(Pawn), 0)) && promotion.is_none() {
+            return Err(ChessError::IllegalMove);
+        }
+
+        let dc = max(c1, c2) - min(c1, c2);
+
+        self.is_not_enemy(&turn, r1, c1)?;
+
+        // Castling: the king steps two files along its home rank.
+        if matches!(self.0[r1][c1], White(King) | Black(King)) && r1 == r2 && dc == 2 {
+            return self.castle(turn, r1, c1, c2);
+        }
+
+        // En-passant capture: a pawn moves diagonally onto the stored target.
+        if matches!(self.0[r1][c1], White(Pawn) | Black(Pawn))
+            && self.2 == Some((r2, c2))
+            && dc == 1
+        {
+            return self.en_passant(turn, r1, c1, r2, c2);
+        }
+
         self.is_legal_move(turn, r1, c1, r2, c2)?;
 
-        self.0[r2][c2] = self.0[r1][c1];
-        let killed = if let White(p) | Black(p) = self.0[r1][c1] {
+        // A move may not leave the mover's own king in check.
+        let mut next = self.clone();
+        next.0[r2][c2] = next.0[r1][c1];
+        next.0[r1][c1] = Empty;
+        if next.is_in_check(turn) {
+            return Err(ChessError::KingInCheck);
+        }
+
+        let captured = self.0[r2][c2];
+        let captured_promoted = self.4[r2][c2];
+        let killed = if let White(p) | Black(p) = captured {
             Some(p)
         } else {
             None
         };
+
+        let moved = self.0[r1][c1];
+        let moved_promoted = self.4[r1][c1];
+        self.0[r2][c2] = moved;
         self.0[r1][c1] = Empty;
+        self.4[r2][c2] = moved_promoted;
+        self.4[r1][c1] = false;
+
+        if killed.is_some() {
+            self.credit_holdings(turn, captured, captured_promoted);
+        }
+
+        // Promotion: a pawn reaching the last rank becomes the chosen piece,
+        // and the destination is flagged so it reverts to a Pawn if captured.
+        if let (White(Pawn), 7) | (Black(Pawn), 0) = (moved, r2) {
+            if let Some(p) = promotion {
+                self.0[r2][c2] = match turn {
+                    Team::White => White(p),
+                    Team::Black => Black(p),
+                };
+                self.4[r2][c2] = true;
+            }
+        }
+
+        self.update_castling_rights(moved, r1, c1);
+
+        // Record a fresh en-passant target on a two-square pawn advance,
+        // otherwise clear any stale one.
+        self.2 = match (moved, max(r1, r2) - min(r1, r2)) {
+            (White(Pawn), 2) => Some((r1 + 1, c1)),
+            (Black(Pawn), 2) => Some((r1 - 1, c1)),
+            _ => None,
+        };
 
         Ok(killed)
     }
 
+    /// Returns a copy of `turn`'s captured-piece holdings.
+    pub fn holdings(&self, turn: Team) -> Holdings {
+        self.3[team_index(turn)]
+    }
+
+    /// Drops a held piece from `turn`'s pocket onto the empty square `to`.
+    ///
+    /// Fails with `NotInHoldings` when the side lacks that piece,
+    /// `MoveToTeamPiece` when the target is occupied, `IllegalMove` when
+    /// asked to drop a king or to place a pawn on the 1st or 8th rank, and
+    /// `KingInCheck` when the drop would leave the dropper's own king in
+    /// check.
+    pub fn drop_piece(&mut self, turn: Team, piece: Piece, to: &str) -> Result<(), ChessError> {
+        use SquareState::*;
+
+        let (row, col) = Board::convert_square_number(to)?;
+
+        if piece == Piece::King {
+            return Err(ChessError::IllegalMove);
+        }
+        if piece == Piece::Pawn && (row == 0 || row == 7) {
+            return Err(ChessError::IllegalMove);
+        }
+        if self.0[row][col] != Empty {
+            return Err(ChessError::MoveToTeamPiece);
+        }
+
+        let held = *self.3[team_index(turn)]
+            .count_mut(piece)
+            .ok_or(ChessError::IllegalMove)?;
+        if held == 0 {
+            return Err(ChessError::NotInHoldings);
+        }
+
+        // A drop may not leave the dropper's own king in check.
+        let mut next = self.clone();
+        next.0[row][col] = match turn {
+            Team::White => White(piece),
+            Team::Black => Black(piece),
+        };
+        if next.is_in_check(turn) {
+            return Err(ChessError::KingInCheck);
+        }
+
+        let count = self.3[team_index(turn)]
+            .count_mut(piece)
+            .expect("validated above");
+        *count -= 1;
+
+        self.0[row][col] = match turn {
+            Team::White => White(piece),
+            Team::Black => Black(piece),
+        };
+        Ok(())
+    }
+
+    /// Credits `capturer`'s holdings with a captured piece. A captured piece
+    /// that was a promoted pawn reverts to a Pawn in the pocket; kings are
+    /// never captured and so contribute nothing.
+    fn credit_holdings(&mut self, capturer: Team, captured: SquareState, was_promoted: bool) {
+        use SquareState::*;
+
+        let piece = match captured {
+            White(p) | Black(p) => p,
+            Empty => return,
+        };
+        let piece = if was_promoted { Piece::Pawn } else { piece };
+        if let Some(count) = self.3[team_index(capturer)].count_mut(piece) {
+            *count += 1;
+        }
+    }
+
+    /// Performs a castling move: the king has already been found to step two
+    /// files along a rank. Validates that this is the home rank, the rights,
+    /// that the rook is home, an empty path, and that the king neither starts, passes through, nor lands on an
+    /// attacked square, then slides the rook to the far side of the king.
+    fn castle(
+        &mut self,
+        turn: Team,
+        row: usize,
+        king_from: usize,
+        king_to: usize,
+    ) -> Result<Option<Piece>, ChessError> {
+        use Piece::*;
+        use SquareState::*;
+
+        let home = if turn == Team::White { 0 } else { 7 };
+        if row != home {
+            return Err(ChessError::IllegalMove);
+        }
+
+        let king_side = king_to > king_from;
+        let (rook_col, allowed) = match (turn, king_side) {
+            (Team::White, true) => (7, self.1.white_king_side),
+            (Team::White, false) => (0, self.1.white_queen_side),
+            (Team::Black, true) => (7, self.1.black_king_side),
+            (Team::Black, false) => (0, self.1.black_queen_side),
+        };
+        if !allowed {
+            return Err(ChessError::IllegalMove);
+        }
+
+        // The rook must still be home.
+        match (turn, self.0[row][rook_col]) {
+            (Team::White, White(Rook)) | (Team::Black, Black(Rook)) => {}
+            _ => return Err(ChessError::IllegalMove),
+        }
+
+        // Every square strictly between king and rook must be empty.
+        let (lo, hi) = (min(king_from, rook_col), max(king_from, rook_col));
+        for col in (lo + 1)..hi {
+            if self.0[row][col] != Empty {
+                return Err(ChessError::IllegalMove);
+            }
+        }
+
+        // The king may not start in, pass through, or land on check.
+        for col in min(king_from, king_to)..=max(king_from, king_to) {
+            let mut probe = self.clone();
+            probe.0[row][col] = probe.0[row][king_from];
+            if col != king_from {
+                probe.0[row][king_from] = Empty;
+            }
+            if probe.is_in_check(turn) {
+                return Err(ChessError::KingInCheck);
+            }
+        }
+
+        let rook_to = if king_side { king_to - 1 } else { king_to + 1 };
+        self.0[row][king_to] = self.0[row][king_from];
+        self.0[row][king_from] = Empty;
+        self.0[row][rook_to] = self.0[row][rook_col];
+        self.0[row][rook_col] = Empty;
+        self.4[row][king_to] = false;
+        self.4[row][rook_to] = false;
+
+        self.clear_castling_rights(turn);
+        self.2 = None;
+
+        Ok(None)
+    }
+
+    /// Performs an en-passant capture onto the stored target square, removing
+    /// the enemy pawn that sits one rank behind the target.
+    fn en_passant(
+        &mut self,
+        turn: Team,
+        r1: usize,
+        c1: usize,
+        r2: usize,
+        c2: usize,
+    ) -> Result<Option<Piece>, ChessError> {
+        use Piece::*;
+        use SquareState::*;
+
+        let correct_rank = match turn {
+            Team::White => r2 == 5,
+            Team::Black => r2 == 2,
+        };
+        if !correct_rank || self.0[r2][c2] != Empty {
+            return Err(ChessError::InvalidEnPassant);
+        }
+
+        // The mover must be stepping one rank forward, not teleporting in from afar.
+        let one_rank_forward = match turn {
+            Team::White => r1 + 1 == r2,
+            Team::Black => r1 == r2 + 1,
+        };
+        if !one_rank_forward {
+            return Err(ChessError::InvalidEnPassant);
+        }
+
+        // The captured pawn shares the target file and the mover's origin rank.
+        match (turn, self.0[r1][c2]) {
+            (Team::White, Black(Pawn)) | (Team::Black, White(Pawn)) => {}
+            _ => return Err(ChessError::InvalidEnPassant),
+        }
+
+        // The capture may not expose the mover's own king.
+        let mut next = self.clone();
+        next.0[r2][c2] = next.0[r1][c1];
+        next.0[r1][c1] = Empty;
+        next.0[r1][c2] = Empty;
+        if next.is_in_check(turn) {
+            return Err(ChessError::KingInCheck);
+        }
+
+        let captured = self.0[r1][c2];
+        self.0[r2][c2] = self.0[r1][c1];
+        self.0[r1][c1] = Empty;
+        self.0[r1][c2] = Empty;
+        self.4[r2][c2] = false;
+        self.4[r1][c1] = false;
+        self.2 = None;
+
+        self.credit_holdings(turn, captured, false);
+
+        Ok(Some(Pawn))
+    }
+
+    /// Clears castling rights affected by moving `piece` off `(row, col)`:
+    /// both rights when the king moves, the matching side when a home rook moves.
+    fn update_castling_rights(&mut self, piece: SquareState, row: usize, col: usize) {
+        use Piece::*;
+        use SquareState::*;
+
+        match piece {
+            White(King) => {
+                self.1.white_king_side = false;
+                self.1.white_queen_side = false;
+            }
+            Black(King) => {
+                self.1.black_king_side = false;
+                self.1.black_queen_side = false;
+            }
+            White(Rook) if row == 0 && col == 0 => self.1.white_queen_side = false,
+            White(Rook) if row == 0 && col == 7 => self.1.white_king_side = false,
+            Black(Rook) if row == 7 && col == 0 => self.1.black_queen_side = false,
+            Black(Rook) if row == 7 && col == 7 => self.1.black_king_side = false,
+            _ => {}
+        }
+    }
+
+    /// The lazily-built, constant-seeded table of Zobrist keys, shared across
+    /// all boards so hashes are comparable and reproducible between runs.
+    fn zobrist_table() -> &'static Zobrist {
+        static TABLE: OnceLock<Zobrist> = OnceLock::new();
+        TABLE.get_or_init(|| {
+            let mut state = 0x0123_4567_89AB_CDEF;
+            let mut pieces = [[0u64; 64]; 12];
+            for slot in pieces.iter_mut() {
+                for key in slot.iter_mut() {
+                    *key = splitmix64(&mut state);
+                }
+            }
+            let mut castling = [0u64; 4];
+            for key in castling.iter_mut() {
+                *key = splitmix64(&mut state);
+            }
+            let mut en_passant = [0u64; 8];
+            for key in en_passant.iter_mut() {
+                *key = splitmix64(&mut state);
+            }
+            let side_to_move = splitmix64(&mut state);
+            Zobrist {
+                pieces,
+                castling,
+                en_passant,
+                side_to_move,
+            }
+        })
+    }
+
+    /// A 64-bit Zobrist hash of the position: the XOR of the keys for every
+    /// occupied square's piece, the active castling rights, the en-passant
+    /// file if one is set, and the side-to-move key when Black is to move.
+    /// Suitable as a transposition-table key or for detecting threefold
+    /// repetition.
+    pub fn zobrist_hash(&self) -> u64 {
+        use Piece::*;
+        use SquareState::*;
+
+        let table = Board::zobrist_table();
+        let piece_offset = |p: Piece| match p {
+            Pawn => 0,
+            Knight => 1,
+            Bishop => 2,
+            Rook => 3,
+            Queen => 4,
+            King => 5,
+        };
+
+        let mut hash = 0;
+        for r in 0..8 {
+            for c in 0..8 {
+                let index = match self.0[r][c] {
+                    White(p) => piece_offset(p),
+                    Black(p) => piece_offset(p) + 6,
+                    Empty => continue,
+                };
+                hash ^= table.pieces[index][r * 8 + c];
+            }
+        }
+
+        if self.1.white_king_side {
+            hash ^= table.castling[0];
+        }
+        if self.1.white_queen_side {
+            hash ^= table.castling[1];
+        }
+        if self.1.black_king_side {
+            hash ^= table.castling[2];
+        }
+        if self.1.black_queen_side {
+            hash ^= table.castling[3];
+        }
+
+        if let Some((_, col)) = self.2 {
+            hash ^= table.en_passant[col];
+        }
+
+        if self.5 == Team::Black {
+            hash ^= table.side_to_move;
+        }
+
+        hash
+    }
+
+    /// Clears both of `turn`'s castling rights (used after the king moves).
+    fn clear_castling_rights(&mut self, turn: Team) {
+        match turn {
+            Team::White => {
+                self.1.white_king_side = false;
+                self.1.white_queen_side = false;
+            }
+            Team::Black => {
+                self.1.black_king_side = false;
+                self.1.black_queen_side = false;
+            }
+        }
+    }
+
+    /// Enumerates every legal move for `turn` as algebraic `(from, to)` pairs
+    /// such as `("2c", "4c")`.
+    ///
+    /// Each friendly piece proposes candidate destinations by type (knight and
+    /// king offsets, sliding rays, pawn pushes and capture diagonals); every
+    /// candidate is run through `is_legal_move`, then discarded if it would
+    /// leave the mover's own king in check. Castling and en-passant candidates
+    /// bypass `is_legal_move` and are validated by trying `castle`/`en_passant`
+    /// on a clone instead, mirroring `move_piece`'s own dispatch.
+    pub fn legal_moves(&self, turn: Team) -> Vec<(String, String)> {
+        use SquareState::*;
+
+        let mut moves = Vec::new();
+        for r1 in 0..8 {
+            for c1 in 0..8 {
+                let piece = match (turn, self.0[r1][c1]) {
+                    (Team::White, White(p)) | (Team::Black, Black(p)) => p,
+                    _ => continue,
+                };
+                for (r2, c2) in self.candidate_destinations(turn, piece, r1, c1) {
+                    let dc = max(c1, c2) - min(c1, c2);
+
+                    // Castling: mirror move_piece's dispatch by trying it on a clone.
+                    if piece == Piece::King && r1 == r2 && dc == 2 {
+                        if self.clone().castle(turn, r1, c1, c2).is_ok() {
+                            moves.push((Board::square_name(r1, c1), Board::square_name(r2, c2)));
+                        }
+                        continue;
+                    }
+
+                    // En-passant capture: same dispatch as move_piece.
+                    if piece == Piece::Pawn && self.2 == Some((r2, c2)) && dc == 1 {
+                        if self.clone().en_passant(turn, r1, c1, r2, c2).is_ok() {
+                            moves.push((Board::square_name(r1, c1), Board::square_name(r2, c2)));
+                        }
+                        continue;
+                    }
+
+                    if self.is_legal_move(turn, r1, c1, r2, c2).is_err() {
+                        continue;
+                    }
+                    let mut next = self.clone();
+                    next.0[r2][c2] = next.0[r1][c1];
+                    next.0[r1][c1] = Empty;
+                    if next.is_in_check(turn) {
+                        continue;
+                    }
+                    moves.push((Board::square_name(r1, c1), Board::square_name(r2, c2)));
+                }
+            }
+        }
+        moves
+    }
+
     /// Confirms that (r1, c1) -> (r2, c2) is a legal move
     /// Returns a corresponding Err(ChessError) if it is not a legal move.
     fn is_legal_move(
@@ -113,6 +858,7 @@ impl Board {
                 match (r2 - r1, dc, self.0[r2][c2]) {
                     (1, 0, Empty) |
                     (1, 1, Black(_)) => Ok(()),
+                    (2, 0, Empty) if r1 == 1 && self.0[r1 + 1][c1] == Empty => Ok(()),
                     _ => Err(ChessError::IllegalMove),
                 }
             }
@@ -120,11 +866,12 @@ impl Board {
                 match (r1 - r2, dc, self.0[r2][c2]) {
                     (1, 0, Empty) |
                     (1, 1, White(_)) => Ok(()),
+                    (2, 0, Empty) if r1 == 6 && self.0[r1 - 1][c1] == Empty => Ok(()),
                     _ => Err(ChessError::IllegalMove),
                 }
             }
             White(Rook) | Black(Rook) => {
-                if dr == 0 || dc == 0 {
+                if (dr == 0 || dc == 0) && self.path_is_clear(r1, c1, r2, c2) {
                     Ok(())
                 } else {
                     Err(ChessError::IllegalMove)
@@ -138,14 +885,14 @@ impl Board {
                 }
             }
             White(Bishop) | Black(Bishop) => {
-                if dr == dc {
+                if dr == dc && self.path_is_clear(r1, c1, r2, c2) {
                     Ok(())
                 } else {
                     Err(ChessError::IllegalMove)
                 }
             }
             White(Queen) | Black(Queen) => {
-                if dr == 0 || dc == 0 || dr == dc {
+                if (dr == 0 || dc == 0 || dr == dc) && self.path_is_clear(r1, c1, r2, c2) {
                     Ok(())
                 } else {
                     Err(ChessError::IllegalMove)
@@ -162,6 +909,230 @@ impl Board {
         }
     }
 
+    /// Returns true when every square strictly between `(r1, c1)` and
+    /// `(r2, c2)` is empty. The step `(sr, sc)` is the per-axis `signum` of
+    /// the displacement, so a rook move has one zero component and a bishop
+    /// move equal nonzero magnitudes. The destination itself is not inspected;
+    /// that case is covered by `is_not_team`.
+    fn path_is_clear(&self, r1: usize, c1: usize, r2: usize, c2: usize) -> bool {
+        let sr = (r2 as isize - r1 as isize).signum();
+        let sc = (c2 as isize - c1 as isize).signum();
+
+        let mut r = r1 as isize + sr;
+        let mut c = c1 as isize + sc;
+        while (r, c) != (r2 as isize, c2 as isize) {
+            if self.0[r as usize][c as usize] != SquareState::Empty {
+                return false;
+            }
+            r += sr;
+            c += sc;
+        }
+        true
+    }
+
+    /// Lists the in-bounds squares a piece of the given type might move to
+    /// from `(r, c)`. These are only geometric proposals; `is_legal_move` is
+    /// still responsible for rejecting blocked or otherwise illegal ones.
+    fn candidate_destinations(
+        &self,
+        turn: Team,
+        piece: Piece,
+        r: usize,
+        c: usize,
+    ) -> Vec<(usize, usize)> {
+        use Piece::*;
+
+        let r = r as isize;
+        let c = c as isize;
+        let mut dests = Vec::new();
+        let push = |dr: isize, dc: isize, dests: &mut Vec<(usize, usize)>| {
+            let (nr, nc) = (r + dr, c + dc);
+            if (0..8).contains(&nr) && (0..8).contains(&nc) {
+                dests.push((nr as usize, nc as usize));
+            }
+        };
+
+        match piece {
+            Knight => {
+                for (dr, dc) in [
+                    (1, 2), (1, -2), (-1, 2), (-1, -2),
+                    (2, 1), (2, -1), (-2, 1), (-2, -1),
+                ] {
+                    push(dr, dc, &mut dests);
+                }
+            }
+            King => {
+                for dr in -1..=1 {
+                    for dc in -1..=1 {
+                        if (dr, dc) != (0, 0) {
+                            push(dr, dc, &mut dests);
+                        }
+                    }
+                }
+                // Castling: the king may also step two files toward a rook.
+                push(0, 2, &mut dests);
+                push(0, -2, &mut dests);
+            }
+            Pawn => {
+                let fwd = if turn == Team::White { 1 } else { -1 };
+                push(fwd, 0, &mut dests);
+                push(2 * fwd, 0, &mut dests);
+                push(fwd, 1, &mut dests);
+                push(fwd, -1, &mut dests);
+            }
+            Rook | Bishop | Queen => {
+                let dirs: &[(isize, isize)] = match piece {
+                    Rook => &[(1, 0), (-1, 0), (0, 1), (0, -1)],
+                    Bishop => &[(1, 1), (1, -1), (-1, 1), (-1, -1)],
+                    _ => &[
+                        (1, 0), (-1, 0), (0, 1), (0, -1),
+                        (1, 1), (1, -1), (-1, 1), (-1, -1),
+                    ],
+                };
+                for &(dr, dc) in dirs {
+                    let (mut nr, mut nc) = (r + dr, c + dc);
+                    while (0..8).contains(&nr) && (0..8).contains(&nc) {
+                        dests.push((nr as usize, nc as usize));
+                        if self.0[nr as usize][nc as usize] != SquareState::Empty {
+                            break;
+                        }
+                        nr += dr;
+                        nc += dc;
+                    }
+                }
+            }
+        }
+        dests
+    }
+
+    /// Locates the square holding `turn`'s king, if present.
+    fn king_square(&self, turn: Team) -> Option<(usize, usize)> {
+        use SquareState::*;
+
+        for r in 0..8 {
+            for c in 0..8 {
+                match (turn, self.0[r][c]) {
+                    (Team::White, White(Piece::King)) |
+                    (Team::Black, Black(Piece::King)) => return Some((r, c)),
+                    _ => {}
+                }
+            }
+        }
+        None
+    }
+
+    /// Reports whether `turn`'s king currently stands on an attacked square.
+    ///
+    /// The king square is probed outward: knight offsets for enemy knights,
+    /// neighbouring squares for the enemy king, sliding rays (orthogonal for
+    /// rook/queen, diagonal for bishop/queen) stopping at the first occupied
+    /// square, and the two diagonals an enemy pawn would capture from.
+    pub fn is_in_check(&self, turn: Team) -> bool {
+        use Piece::*;
+        use SquareState::*;
+
+        let (kr, kc) = match self.king_square(turn) {
+            Some(sq) => sq,
+            None => return false,
+        };
+        let (kr, kc) = (kr as isize, kc as isize);
+
+        let enemy = |sq: SquareState| -> Option<Piece> {
+            match (turn, sq) {
+                (Team::White, Black(p)) | (Team::Black, White(p)) => Some(p),
+                _ => None,
+            }
+        };
+        let at = |r: isize, c: isize| -> Option<SquareState> {
+            if (0..8).contains(&r) && (0..8).contains(&c) {
+                Some(self.0[r as usize][c as usize])
+            } else {
+                None
+            }
+        };
+
+        // Knights.
+        for (dr, dc) in [
+            (1, 2), (1, -2), (-1, 2), (-1, -2),
+            (2, 1), (2, -1), (-2, 1), (-2, -1),
+        ] {
+            if let Some(sq) = at(kr + dr, kc + dc) {
+                if enemy(sq) == Some(Knight) {
+                    return true;
+                }
+            }
+        }
+
+        // Adjacent enemy king.
+        for dr in -1..=1 {
+            for dc in -1..=1 {
+                if (dr, dc) == (0, 0) {
+                    continue;
+                }
+                if let Some(sq) = at(kr + dr, kc + dc) {
+                    if enemy(sq) == Some(King) {
+                        return true;
+                    }
+                }
+            }
+        }
+
+        // Sliding rays.
+        let rays: [((isize, isize), bool); 8] = [
+            ((1, 0), true), ((-1, 0), true), ((0, 1), true), ((0, -1), true),
+            ((1, 1), false), ((1, -1), false), ((-1, 1), false), ((-1, -1), false),
+        ];
+        for ((dr, dc), orthogonal) in rays {
+            let (mut r, mut c) = (kr + dr, kc + dc);
+            while let Some(sq) = at(r, c) {
+                if let Some(p) = enemy(sq) {
+                    let hits = match p {
+                        Queen => true,
+                        Rook => orthogonal,
+                        Bishop => !orthogonal,
+                        _ => false,
+                    };
+                    if hits {
+                        return true;
+                    }
+                }
+                if sq != Empty {
+                    break;
+                }
+                r += dr;
+                c += dc;
+            }
+        }
+
+        // Enemy pawns capture toward the king, so they sit one rank ahead of it.
+        let pawn_dir = if turn == Team::White { 1 } else { -1 };
+        for dc in [-1, 1] {
+            if let Some(sq) = at(kr + pawn_dir, kc + dc) {
+                if enemy(sq) == Some(Pawn) {
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
+    /// True when `turn` is in check and has no legal move to escape it.
+    pub fn is_checkmate(&self, turn: Team) -> bool {
+        self.is_in_check(turn) && self.legal_moves(turn).is_empty()
+    }
+
+    /// True when `turn` is not in check yet has no legal move available.
+    pub fn is_stalemate(&self, turn: Team) -> bool {
+        !self.is_in_check(turn) && self.legal_moves(turn).is_empty()
+    }
+
+    /// Converts array indices back into this crate's `"<rank><file>"`
+    /// algebraic form — the inverse of `convert_square_number`.
+    fn square_name(row: usize, col: usize) -> String {
+        format!("{}{}", (b'1' + row as u8) as char, (b'a' + col as u8) as char)
+    }
+
     /// Returns Err when the given square (should be `from`) is empty.
     fn is_not_enemy(&self, turn: &Team, row: usize, col: usize) -> Result<(), ChessError> {
         match (turn, self.0[row][col]) {
@@ -244,7 +1215,7 @@ mod test_board {
     #[test]
     fn test_pawn_to_empty() {
         let mut board = Board::new();
-        let _ = board.move_piece(Team::White, "2c", "3c");
+        let _ = board.move_piece(Team::White, "2c", "3c", None);
 
         let expected = Board([
             [White(Rook), White(Knight), White(Bishop), White(King), White(Queen), White(Bishop), White(Knight), White(Rook)],
@@ -254,12 +1225,44 @@ mod test_board {
             [Empty; 8],
             [Empty; 8],
             [Black(Pawn); 8],
-            [Black(Rook), Black(Knight), Black(Bishop), Black(King), Black(Queen), Black(Bishop), Black(Knight), Black(Rook)], 
-        ]);
+            [Black(Rook), Black(Knight), Black(Bishop), Black(King), Black(Queen), Black(Bishop), Black(Knight), Black(Rook)],
+        ], CastlingRights::all(), None, [Holdings::default(); 2], [[false; 8]; 8], Team::White);
 
         assert_eq!(board, expected);
     }
 
+    #[test]
+    fn test_to_fen_start_position() {
+        let board = Board::new();
+        assert_eq!(
+            board.to_fen(),
+            "rnbkqbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBKQBNR w KQkq - 0 1"
+        );
+    }
+
+    #[test]
+    fn test_from_fen_round_trip() {
+        let board = Board::new();
+        let parsed = Board::from_fen(&board.to_fen()).unwrap();
+        assert_eq!(board, parsed);
+    }
+
+    #[test]
+    fn test_to_fen_preserves_side_to_move() {
+        let board = Board::from_fen("4k3/8/8/8/8/8/8/4K3 b - - 0 1").unwrap();
+        assert_eq!(board.to_fen(), "4k3/8/8/8/8/8/8/4K3 b - - 0 1");
+    }
+
+    #[test]
+    fn test_from_fen_err() {
+        assert_eq!(Board::from_fen("not a fen"), Err(ChessError::InvalidFen));
+        // Placement with no kings.
+        assert_eq!(
+            Board::from_fen("8/8/8/8/8/8/8/8 w - - 0 1"),
+            Err(ChessError::InvalidFen)
+        );
+    }
+
     #[test]
     fn test_is_legal_move() {
         let mut board = Board::new();
@@ -273,6 +1276,188 @@ mod test_board {
 
         assert_eq!(board.is_legal_move(Team::White, 3, 2, 4, 3), Ok(()));
     }
+
+    #[test]
+    fn test_legal_moves_start_position() {
+        let board = Board::new();
+        // 16 pawn moves (single and double push) plus 4 knight moves.
+        assert_eq!(board.legal_moves(Team::White).len(), 20);
+        assert_eq!(board.legal_moves(Team::Black).len(), 20);
+    }
+
+    #[test]
+    fn test_checkmate() {
+        let board = Board::from_fen("8/8/8/8/8/2k5/1q6/K7 w - - 0 1").unwrap();
+        assert!(board.is_in_check(Team::White));
+        assert!(board.is_checkmate(Team::White));
+        assert!(!board.is_stalemate(Team::White));
+    }
+
+    #[test]
+    fn test_stalemate() {
+        let board = Board::from_fen("7k/8/8/8/8/8/2q5/K7 w - - 0 1").unwrap();
+        assert!(!board.is_in_check(Team::White));
+        assert!(board.is_stalemate(Team::White));
+        assert!(!board.is_checkmate(Team::White));
+    }
+
+    #[test]
+    fn test_move_into_check_rejected() {
+        // White king on a1 may not step onto a2, covered by the black rook on h2.
+        let mut board = Board::from_fen("7k/8/8/8/8/8/7r/K7 w - - 0 1").unwrap();
+        assert_eq!(
+            board.move_piece(Team::White, "1a", "2a", None),
+            Err(ChessError::KingInCheck)
+        );
+    }
+
+    #[test]
+    fn test_zobrist_hash_stable_and_distinct() {
+        // Deterministic: the same position always hashes identically.
+        assert_eq!(Board::new().zobrist_hash(), Board::new().zobrist_hash());
+
+        // Distinct positions (here, differing castling rights) differ.
+        let a = Board::from_fen("4k3/8/8/8/8/8/8/4K3 w KQkq - 0 1").unwrap();
+        let b = Board::from_fen("4k3/8/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        assert_ne!(a.zobrist_hash(), b.zobrist_hash());
+
+        // Identical except for whose move it is: still distinct.
+        let white_to_move = Board::from_fen("4k3/8/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        let black_to_move = Board::from_fen("4k3/8/8/8/8/8/8/4K3 b - - 0 1").unwrap();
+        assert_ne!(white_to_move.zobrist_hash(), black_to_move.zobrist_hash());
+    }
+
+    #[test]
+    fn test_castle_king_side() {
+        let mut board = Board::from_fen("3k4/8/8/8/8/8/8/3K3R w KQkq - 0 1").unwrap();
+        assert_eq!(board.move_piece(Team::White, "1d", "1f", None), Ok(None));
+        assert_eq!(board.0[0][5], White(King));
+        assert_eq!(board.0[0][4], White(Rook));
+        assert_eq!(board.0[0][3], Empty);
+        assert_eq!(board.0[0][7], Empty);
+    }
+
+    #[test]
+    fn test_en_passant() {
+        let mut board =
+            Board::from_fen("4k3/8/8/3pP3/8/8/8/4K3 w KQkq d6 0 1").unwrap();
+        assert_eq!(
+            board.move_piece(Team::White, "5e", "6d", None),
+            Ok(Some(Pawn))
+        );
+        assert_eq!(board.0[5][3], White(Pawn));
+        assert_eq!(board.0[4][4], Empty);
+        assert_eq!(board.0[4][3], Empty);
+    }
+
+    #[test]
+    fn test_en_passant_rejects_non_adjacent_rank() {
+        // A pawn on d2 is nowhere near the stored e6 en-passant target, even
+        // though an enemy pawn happens to sit on e2, same rank as the mover.
+        let mut board =
+            Board::from_fen("4k3/8/8/8/8/8/3Pp3/4K3 w - e6 0 1").unwrap();
+        assert_eq!(
+            board.move_piece(Team::White, "2d", "6e", None),
+            Err(ChessError::InvalidEnPassant)
+        );
+    }
+
+    #[test]
+    fn test_promotion() {
+        let mut board = Board::from_fen("4k3/P7/8/8/8/8/8/4K3 w KQkq - 0 1").unwrap();
+        assert_eq!(
+            board.move_piece(Team::White, "7a", "8a", Some(Queen)),
+            Ok(None)
+        );
+        assert_eq!(board.0[7][0], White(Queen));
+    }
+
+    #[test]
+    fn test_promotion_required() {
+        let mut board = Board::from_fen("4k3/P7/8/8/8/8/8/4K3 w KQkq - 0 1").unwrap();
+        assert_eq!(
+            board.move_piece(Team::White, "7a", "8a", None),
+            Err(ChessError::IllegalMove)
+        );
+        // The pawn never moved.
+        assert_eq!(board.0[6][0], White(Pawn));
+        assert_eq!(board.0[7][0], Empty);
+    }
+
+    #[test]
+    fn test_capture_credits_holdings_and_drop() {
+        let mut board =
+            Board::from_fen("4k3/8/8/n7/8/8/8/R3K3 w KQkq - 0 1").unwrap();
+
+        // The rook captures the knight, which lands in White's pocket.
+        assert_eq!(
+            board.move_piece(Team::White, "1a", "5a", None),
+            Ok(Some(Knight))
+        );
+        assert_eq!(board.holdings(Team::White).knight, 1);
+
+        // That knight can then be dropped onto an empty square.
+        assert_eq!(board.drop_piece(Team::White, Knight, "4d"), Ok(()));
+        assert_eq!(board.0[3][3], White(Knight));
+        assert_eq!(board.holdings(Team::White).knight, 0);
+    }
+
+    #[test]
+    fn test_drop_errors() {
+        let mut board = Board::new();
+        // Nothing in the pocket yet.
+        assert_eq!(
+            board.drop_piece(Team::White, Queen, "4e"),
+            Err(ChessError::NotInHoldings)
+        );
+        // Pawns may never be dropped on the back ranks.
+        assert_eq!(
+            board.drop_piece(Team::White, Pawn, "8d"),
+            Err(ChessError::IllegalMove)
+        );
+    }
+
+    #[test]
+    fn test_drop_into_check_rejected() {
+        let mut board =
+            Board::from_fen("6k1/8/8/n6r/8/8/8/R3K3 w - - 0 1").unwrap();
+
+        // White captures the knight, landing it in the pocket.
+        assert_eq!(
+            board.move_piece(Team::White, "1a", "5a", None),
+            Ok(Some(Knight))
+        );
+        // Black swings its rook onto the e-file, checking the White king.
+        assert_eq!(board.move_piece(Team::Black, "5h", "5e", None), Ok(None));
+
+        // Dropping the knight on d1 neither blocks nor captures the check.
+        assert_eq!(
+            board.drop_piece(Team::White, Knight, "1d"),
+            Err(ChessError::KingInCheck)
+        );
+    }
+
+    #[test]
+    fn test_sliding_blocked() {
+        let board = Board::new();
+        // Rook on '1a' is hemmed in by its own pawn and neighbour.
+        assert_eq!(
+            board.is_legal_move(Team::White, 0, 0, 3, 0),
+            Err(ChessError::IllegalMove)
+        );
+    }
+
+    #[test]
+    fn test_pawn_double_push() {
+        let board = Board::new();
+        assert_eq!(board.is_legal_move(Team::White, 1, 4, 3, 4), Ok(()));
+        assert_eq!(board.is_legal_move(Team::Black, 6, 4, 4, 4), Ok(()));
+        // A three-square push is never legal.
+        assert_eq!(
+            board.is_legal_move(Team::White, 1, 4, 4, 4),
+            Err(ChessError::IllegalMove)
+        );
+    }
 }
 
 impl std::fmt::Display for SquareState {